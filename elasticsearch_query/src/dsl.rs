@@ -1,8 +1,10 @@
 use core::fmt;
+use std::marker::PhantomData;
 
 use bigdecimal::BigDecimal;
+use serde::de::{self, MapAccess, Visitor};
 use serde::ser::SerializeMap;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A query clauses which represent an Elasticserach Leaf Query DSL.
 /// [Query DSL]: <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html>
@@ -26,6 +28,76 @@ pub enum QueryClause {
         search_val: String,
         is_case_insensitive: bool,
     },
+    Wildcard {
+        field: String,
+        value: String,
+        case_insensitive: bool,
+    },
+    Regexp {
+        field: String,
+        value: String,
+        flags: Option<String>,
+        case_insensitive: bool,
+    },
+    Exists {
+        field: String,
+    },
+    Ids {
+        values: Vec<String>,
+    },
+    Fuzzy {
+        field: String,
+        value: String,
+        fuzziness: Fuzziness,
+        prefix_length: Option<u32>,
+        max_expansions: Option<u32>,
+    },
+}
+
+/// The allowed edit distance for a `Fuzzy` query, either an explicit number
+/// of edits or Elasticsearch's `AUTO` rule.
+/// [Fuzziness]: <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#fuzziness>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fuzziness {
+    Exact(u8),
+    Auto { low: u32, high: u32 },
+}
+
+impl Fuzziness {
+    /// The number of edits Elasticsearch's `AUTO` rule allows for a term of
+    /// `term_len`: `0` below `low`, `1` from `low` up to (excluding) `high`,
+    /// `2` at or above `high`. `Exact` ignores `term_len` entirely.
+    pub fn allowed_edits(&self, term_len: usize) -> u8 {
+        match self {
+            Fuzziness::Exact(edits) => *edits,
+            Fuzziness::Auto { low, high } => {
+                if term_len < *low as usize {
+                    0
+                } else if term_len < *high as usize {
+                    1
+                } else {
+                    2
+                }
+            }
+        }
+    }
+}
+
+impl Serialize for Fuzziness {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Fuzziness::Exact(edits) => serializer.serialize_u8(*edits),
+            Fuzziness::Auto { low, high } if *low == 3 && *high == 6 => {
+                serializer.serialize_str("AUTO")
+            }
+            Fuzziness::Auto { low, high } => {
+                serializer.serialize_str(&format!("AUTO:{low},{high}"))
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -61,9 +133,18 @@ impl Serialize for QuerySort {
 }
 
 pub struct InnerQueryClause<'a>(&'a QueryClause);
-pub struct InnerRange<'a>(&'a BigDecimal, &'a BigDecimal);
+pub struct InnerRange<'a>(&'a BigDecimal, &'a BigDecimal, &'a Option<f32>);
 
-pub struct InnerPrefix<'a>(&'a String, &'a bool);
+pub struct InnerPrefix<'a>(&'a String, &'a bool, &'a Option<f32>);
+pub struct InnerWildcard<'a>(&'a String, &'a bool, &'a Option<f32>);
+pub struct InnerRegexp<'a>(&'a String, &'a Option<String>, &'a bool, &'a Option<f32>);
+pub struct InnerFuzzy<'a>(
+    &'a String,
+    &'a Fuzziness,
+    &'a Option<u32>,
+    &'a Option<u32>,
+    &'a Option<f32>,
+);
 
 impl<'a> Serialize for InnerQueryClause<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -79,7 +160,7 @@ impl<'a> Serialize for InnerQueryClause<'a> {
             }
             QueryClause::Range { field, gte, lte } => {
                 let mut map = serializer.serialize_map(Some(1))?;
-                map.serialize_entry(field, &InnerRange(gte, lte))?;
+                map.serialize_entry(field, &InnerRange(gte, lte, &None))?;
                 map.end()
             }
             QueryClause::Terms { field, search_val } => {
@@ -93,7 +174,50 @@ impl<'a> Serialize for InnerQueryClause<'a> {
                 is_case_insensitive,
             } => {
                 let mut map = serializer.serialize_map(Some(1))?;
-                map.serialize_entry(field, &InnerPrefix(search_val, is_case_insensitive))?;
+                map.serialize_entry(field, &InnerPrefix(search_val, is_case_insensitive, &None))?;
+                map.end()
+            }
+            QueryClause::Wildcard {
+                field,
+                value,
+                case_insensitive,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(field, &InnerWildcard(value, case_insensitive, &None))?;
+                map.end()
+            }
+            QueryClause::Regexp {
+                field,
+                value,
+                flags,
+                case_insensitive,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(field, &InnerRegexp(value, flags, case_insensitive, &None))?;
+                map.end()
+            }
+            QueryClause::Exists { field } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("field", field)?;
+                map.end()
+            }
+            QueryClause::Ids { values } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("values", values)?;
+                map.end()
+            }
+            QueryClause::Fuzzy {
+                field,
+                value,
+                fuzziness,
+                prefix_length,
+                max_expansions,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(
+                    field,
+                    &InnerFuzzy(value, fuzziness, prefix_length, max_expansions, &None),
+                )?;
                 map.end()
             }
         }
@@ -105,9 +229,13 @@ impl<'a> Serialize for InnerRange<'a> {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(2))?;
+        let len = 2 + self.2.is_some() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
         map.serialize_entry("gte", self.0)?;
         map.serialize_entry("lte", self.1)?;
+        if let Some(boost) = self.2 {
+            map.serialize_entry("boost", boost)?;
+        }
         map.end()
     }
 }
@@ -117,35 +245,731 @@ impl<'a> Serialize for InnerPrefix<'a> {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(2))?;
+        let len = 2 + self.2.is_some() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
         map.serialize_entry("value", self.0)?;
         map.serialize_entry("case_insensitive", self.1)?;
+        if let Some(boost) = self.2 {
+            map.serialize_entry("boost", boost)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for InnerWildcard<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let len = 2 + self.2.is_some() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("value", self.0)?;
+        map.serialize_entry("case_insensitive", self.1)?;
+        if let Some(boost) = self.2 {
+            map.serialize_entry("boost", boost)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for InnerRegexp<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let len = 2 + self.1.is_some() as usize + self.3.is_some() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("value", self.0)?;
+        if let Some(flags) = self.1 {
+            map.serialize_entry("flags", flags)?;
+        }
+        map.serialize_entry("case_insensitive", self.2)?;
+        if let Some(boost) = self.3 {
+            map.serialize_entry("boost", boost)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for InnerFuzzy<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let len = 2
+            + self.2.is_some() as usize
+            + self.3.is_some() as usize
+            + self.4.is_some() as usize;
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("value", self.0)?;
+        map.serialize_entry("fuzziness", self.1)?;
+        if let Some(prefix_length) = self.2 {
+            map.serialize_entry("prefix_length", prefix_length)?;
+        }
+        if let Some(max_expansions) = self.3 {
+            map.serialize_entry("max_expansions", max_expansions)?;
+        }
+        if let Some(boost) = self.4 {
+            map.serialize_entry("boost", boost)?;
+        }
         map.end()
     }
 }
 
+/// The outer Query DSL key a `QueryClause` serializes under, e.g. `"match"`.
+fn clause_key(query: &QueryClause) -> &'static str {
+    match query {
+        QueryClause::Match { .. } => "match",
+        QueryClause::Range { .. } => "range",
+        QueryClause::Terms { .. } => "terms",
+        QueryClause::Prefix { .. } => "prefix",
+        QueryClause::Wildcard { .. } => "wildcard",
+        QueryClause::Regexp { .. } => "regexp",
+        QueryClause::Exists { .. } => "exists",
+        QueryClause::Ids { .. } => "ids",
+        QueryClause::Fuzzy { .. } => "fuzzy",
+    }
+}
+
 impl Serialize for QueryClause {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         let mut map = serializer.serialize_map(Some(1))?;
-        match self {
-            q @ QueryClause::Match { .. } => map.serialize_entry("match", &InnerQueryClause(q))?,
-            q @ QueryClause::Range { .. } => map.serialize_entry("range", &InnerQueryClause(q))?,
-            q @ QueryClause::Terms { .. } => map.serialize_entry("terms", &InnerQueryClause(q))?,
-            q @ QueryClause::Prefix { .. } => {
-                map.serialize_entry("prefix", &InnerQueryClause(q))?
+        map.serialize_entry(clause_key(self), &InnerQueryClause(self))?;
+        map.end()
+    }
+}
+
+struct InnerMatchBoosted<'a>(&'a String, f32);
+
+impl<'a> Serialize for InnerMatchBoosted<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("query", self.0)?;
+        map.serialize_entry("boost", &self.1)?;
+        map.end()
+    }
+}
+
+struct InnerQueryClauseBoosted<'a>(&'a QueryClause, f32);
+
+impl<'a> Serialize for InnerQueryClauseBoosted<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let query = self.0;
+        let boost = self.1;
+        match query {
+            QueryClause::Match { field, search_val } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(field, &InnerMatchBoosted(search_val, boost))?;
+                map.end()
+            }
+            QueryClause::Range { field, gte, lte } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(field, &InnerRange(gte, lte, &Some(boost)))?;
+                map.end()
+            }
+            QueryClause::Terms { field, search_val } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(field, search_val)?;
+                map.serialize_entry("boost", &boost)?;
+                map.end()
+            }
+            QueryClause::Prefix {
+                field,
+                search_val,
+                is_case_insensitive,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(
+                    field,
+                    &InnerPrefix(search_val, is_case_insensitive, &Some(boost)),
+                )?;
+                map.end()
+            }
+            QueryClause::Wildcard {
+                field,
+                value,
+                case_insensitive,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(
+                    field,
+                    &InnerWildcard(value, case_insensitive, &Some(boost)),
+                )?;
+                map.end()
+            }
+            QueryClause::Regexp {
+                field,
+                value,
+                flags,
+                case_insensitive,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(
+                    field,
+                    &InnerRegexp(value, flags, case_insensitive, &Some(boost)),
+                )?;
+                map.end()
+            }
+            QueryClause::Exists { field } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("field", field)?;
+                map.serialize_entry("boost", &boost)?;
+                map.end()
+            }
+            QueryClause::Ids { values } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("values", values)?;
+                map.serialize_entry("boost", &boost)?;
+                map.end()
+            }
+            QueryClause::Fuzzy {
+                field,
+                value,
+                fuzziness,
+                prefix_length,
+                max_expansions,
+            } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(
+                    field,
+                    &InnerFuzzy(
+                        value,
+                        fuzziness,
+                        prefix_length,
+                        max_expansions,
+                        &Some(boost),
+                    ),
+                )?;
+                map.end()
             }
         }
+    }
+}
+
+/// A `QueryClause` with an optional relevance `boost`, Elasticsearch's
+/// weighting parameter accepted by nearly every leaf query. Produced by
+/// `QueryClause::boost`; serializes to the clause's usual shape when `boost`
+/// is `None`. When set, `"boost"` goes inside the clause's per-field object
+/// (`match` switches to its expanded form, e.g.
+/// `{"match": {field: {"query": val, "boost": 2.0}}}`), except for `terms`,
+/// `exists` and `ids`, where it sits next to the clause's other entries.
+/// [Relevance scores]: <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-bool-query.html>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boosted {
+    pub inner: QueryClause,
+    pub boost: Option<f32>,
+}
+
+impl Serialize for Boosted {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let Some(boost) = self.boost else {
+            return self.inner.serialize(serializer);
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            clause_key(&self.inner),
+            &InnerQueryClauseBoosted(&self.inner, boost),
+        )?;
         map.end()
     }
 }
 
+impl QueryClause {
+    pub fn match_(field: impl Into<String>, search_val: impl Into<String>) -> Self {
+        QueryClause::Match {
+            field: field.into(),
+            search_val: search_val.into(),
+        }
+    }
+
+    pub fn range(field: impl Into<String>, gte: BigDecimal, lte: BigDecimal) -> Self {
+        QueryClause::Range {
+            field: field.into(),
+            gte,
+            lte,
+        }
+    }
+
+    pub fn terms(field: impl Into<String>, search_val: Vec<String>) -> Self {
+        QueryClause::Terms {
+            field: field.into(),
+            search_val,
+        }
+    }
+
+    pub fn prefix(
+        field: impl Into<String>,
+        search_val: impl Into<String>,
+        is_case_insensitive: bool,
+    ) -> Self {
+        QueryClause::Prefix {
+            field: field.into(),
+            search_val: search_val.into(),
+            is_case_insensitive,
+        }
+    }
+
+    pub fn wildcard(
+        field: impl Into<String>,
+        value: impl Into<String>,
+        case_insensitive: bool,
+    ) -> Self {
+        QueryClause::Wildcard {
+            field: field.into(),
+            value: value.into(),
+            case_insensitive,
+        }
+    }
+
+    pub fn regexp(
+        field: impl Into<String>,
+        value: impl Into<String>,
+        flags: Option<String>,
+        case_insensitive: bool,
+    ) -> Self {
+        QueryClause::Regexp {
+            field: field.into(),
+            value: value.into(),
+            flags,
+            case_insensitive,
+        }
+    }
+
+    pub fn exists(field: impl Into<String>) -> Self {
+        QueryClause::Exists {
+            field: field.into(),
+        }
+    }
+
+    pub fn ids(values: Vec<String>) -> Self {
+        QueryClause::Ids { values }
+    }
+
+    pub fn fuzzy(
+        field: impl Into<String>,
+        value: impl Into<String>,
+        fuzziness: Fuzziness,
+        prefix_length: Option<u32>,
+        max_expansions: Option<u32>,
+    ) -> Self {
+        QueryClause::Fuzzy {
+            field: field.into(),
+            value: value.into(),
+            fuzziness,
+            prefix_length,
+            max_expansions,
+        }
+    }
+
+    /// Attach a relevance `boost`, emitted with the clause when serialized
+    /// (see `Boosted` for where it is placed).
+    pub fn boost(self, boost: f32) -> Boosted {
+        Boosted {
+            inner: self,
+            boost: Some(boost),
+        }
+    }
+}
+
+/// A single-entry map of a field name to an arbitrary value, e.g.
+/// `{"risk_spectrum": {"gte": "1", "lte": "5"}}`. Several clauses (`range`,
+/// `terms`, `prefix`, ...) are shaped this way, with the field name itself
+/// used as the map key, so this is shared deserialization plumbing for them.
+struct FieldEntry<T> {
+    field: String,
+    value: T,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FieldEntry<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct FieldEntryVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for FieldEntryVisitor<T> {
+            type Value = FieldEntry<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a single-entry map of field name to value")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let field = map
+                    .next_key()?
+                    .ok_or_else(|| de::Error::custom("missing field name"))?;
+                let value = map.next_value()?;
+                Ok(FieldEntry { field, value })
+            }
+        }
+
+        deserializer.deserialize_map(FieldEntryVisitor(PhantomData))
+    }
+}
+
+#[derive(Deserialize)]
+struct RangeValue {
+    gte: BigDecimal,
+    lte: BigDecimal,
+}
+
+#[derive(Deserialize)]
+struct PrefixValue {
+    value: String,
+    #[serde(default)]
+    case_insensitive: bool,
+}
+
+#[derive(Deserialize)]
+struct WildcardValue {
+    value: String,
+    #[serde(default)]
+    case_insensitive: bool,
+}
+
+#[derive(Deserialize)]
+struct RegexpValue {
+    value: String,
+    #[serde(default)]
+    flags: Option<String>,
+    #[serde(default)]
+    case_insensitive: bool,
+}
+
+#[derive(Deserialize)]
+struct ExistsValue {
+    field: String,
+}
+
+#[derive(Deserialize)]
+struct IdsValue {
+    values: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct FuzzyValue {
+    value: String,
+    fuzziness: Fuzziness,
+    #[serde(default)]
+    prefix_length: Option<u32>,
+    #[serde(default)]
+    max_expansions: Option<u32>,
+}
+
+impl<'de> Deserialize<'de> for Fuzziness {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct FuzzinessVisitor;
+
+        impl<'de> Visitor<'de> for FuzzinessVisitor {
+            type Value = Fuzziness;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(r#"an edit distance, e.g. 2, "AUTO" or "AUTO:low,high""#)
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Fuzziness::Exact(v as u8))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if v == "AUTO" {
+                    return Ok(Fuzziness::Auto { low: 3, high: 6 });
+                }
+                let Some(bounds) = v.strip_prefix("AUTO:") else {
+                    return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self));
+                };
+                let (low, high) = bounds
+                    .split_once(',')
+                    .and_then(|(low, high)| Some((low.parse().ok()?, high.parse().ok()?)))
+                    .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(v), &self))?;
+                Ok(Fuzziness::Auto { low, high })
+            }
+        }
+
+        deserializer.deserialize_any(FuzzinessVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct QueryClauseVisitor;
+
+        impl<'de> Visitor<'de> for QueryClauseVisitor {
+            type Value = QueryClause;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(
+                    "a query clause object with a single `match`, `range`, `terms`, `prefix`, \
+                     `wildcard`, `regexp`, `exists`, `ids` or `fuzzy` key",
+                )
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let key: String = map
+                    .next_key()?
+                    .ok_or_else(|| de::Error::custom("missing query clause key"))?;
+                match key.as_str() {
+                    "match" => {
+                        let FieldEntry { field, value } = map.next_value::<FieldEntry<String>>()?;
+                        Ok(QueryClause::Match {
+                            field,
+                            search_val: value,
+                        })
+                    }
+                    "range" => {
+                        let FieldEntry { field, value } =
+                            map.next_value::<FieldEntry<RangeValue>>()?;
+                        Ok(QueryClause::Range {
+                            field,
+                            gte: value.gte,
+                            lte: value.lte,
+                        })
+                    }
+                    "terms" => {
+                        let FieldEntry { field, value } =
+                            map.next_value::<FieldEntry<Vec<String>>>()?;
+                        Ok(QueryClause::Terms {
+                            field,
+                            search_val: value,
+                        })
+                    }
+                    "prefix" => {
+                        let FieldEntry { field, value } =
+                            map.next_value::<FieldEntry<PrefixValue>>()?;
+                        Ok(QueryClause::Prefix {
+                            field,
+                            search_val: value.value,
+                            is_case_insensitive: value.case_insensitive,
+                        })
+                    }
+                    "wildcard" => {
+                        let FieldEntry { field, value } =
+                            map.next_value::<FieldEntry<WildcardValue>>()?;
+                        Ok(QueryClause::Wildcard {
+                            field,
+                            value: value.value,
+                            case_insensitive: value.case_insensitive,
+                        })
+                    }
+                    "regexp" => {
+                        let FieldEntry { field, value } =
+                            map.next_value::<FieldEntry<RegexpValue>>()?;
+                        Ok(QueryClause::Regexp {
+                            field,
+                            value: value.value,
+                            flags: value.flags,
+                            case_insensitive: value.case_insensitive,
+                        })
+                    }
+                    "exists" => {
+                        let value = map.next_value::<ExistsValue>()?;
+                        Ok(QueryClause::Exists { field: value.field })
+                    }
+                    "ids" => {
+                        let value = map.next_value::<IdsValue>()?;
+                        Ok(QueryClause::Ids {
+                            values: value.values,
+                        })
+                    }
+                    "fuzzy" => {
+                        let FieldEntry { field, value } =
+                            map.next_value::<FieldEntry<FuzzyValue>>()?;
+                        Ok(QueryClause::Fuzzy {
+                            field,
+                            value: value.value,
+                            fuzziness: value.fuzziness,
+                            prefix_length: value.prefix_length,
+                            max_expansions: value.max_expansions,
+                        })
+                    }
+                    other => Err(de::Error::unknown_field(
+                        other,
+                        &[
+                            "match", "range", "terms", "prefix", "wildcard", "regexp", "exists",
+                            "ids", "fuzzy",
+                        ],
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_map(QueryClauseVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for SortType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SortTypeVisitor;
+
+        impl<'de> Visitor<'de> for SortTypeVisitor {
+            type Value = SortType;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(r#""asc" or "desc""#)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match v {
+                    "asc" => Ok(SortType::Asc),
+                    "desc" => Ok(SortType::Desc),
+                    other => Err(de::Error::unknown_variant(other, &["asc", "desc"])),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(SortTypeVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for QuerySort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let FieldEntry { field, value } = FieldEntry::<SortType>::deserialize(deserializer)?;
+        Ok(QuerySort {
+            field_name: field,
+            ordering: value,
+        })
+    }
+}
+
 pub trait ToClause {
     fn to_clause(&self, field: String) -> QueryClause;
 }
 
+/// A single entry in one of `BoolQuery`'s clause lists: either a leaf
+/// `QueryClause` or a nested `BoolQuery`, allowing compound queries to be
+/// built up from other compound queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Clause {
+    Leaf(QueryClause),
+    Compound(Box<BoolQuery>),
+}
+
+impl From<QueryClause> for Clause {
+    fn from(query: QueryClause) -> Self {
+        Clause::Leaf(query)
+    }
+}
+
+impl From<BoolQuery> for Clause {
+    fn from(query: BoolQuery) -> Self {
+        Clause::Compound(Box::new(query))
+    }
+}
+
+impl Serialize for Clause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Clause::Leaf(query) => query.serialize(serializer),
+            Clause::Compound(query) => query.serialize(serializer),
+        }
+    }
+}
+
+/// A compound query which combines other query clauses using Elasticsearch's
+/// `bool` query.
+/// [Bool Query]: <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-bool-query.html>
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BoolQuery {
+    pub must: Vec<Clause>,
+    pub should: Vec<Clause>,
+    pub must_not: Vec<Clause>,
+    pub filter: Vec<Clause>,
+    pub minimum_should_match: Option<i32>,
+}
+
+pub struct InnerBoolQuery<'a>(&'a BoolQuery);
+
+impl<'a> Serialize for InnerBoolQuery<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let query = self.0;
+        let len = [
+            !query.must.is_empty(),
+            !query.should.is_empty(),
+            !query.must_not.is_empty(),
+            !query.filter.is_empty(),
+            query.minimum_should_match.is_some(),
+        ]
+        .into_iter()
+        .filter(|present| *present)
+        .count();
+
+        let mut map = serializer.serialize_map(Some(len))?;
+        if !query.must.is_empty() {
+            map.serialize_entry("must", &query.must)?;
+        }
+        if !query.should.is_empty() {
+            map.serialize_entry("should", &query.should)?;
+        }
+        if !query.must_not.is_empty() {
+            map.serialize_entry("must_not", &query.must_not)?;
+        }
+        if !query.filter.is_empty() {
+            map.serialize_entry("filter", &query.filter)?;
+        }
+        if let Some(minimum_should_match) = &query.minimum_should_match {
+            map.serialize_entry("minimum_should_match", minimum_should_match)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for BoolQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("bool", &InnerBoolQuery(self))?;
+        map.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -190,7 +1014,7 @@ mod tests {
     }
 
     #[test]
-    fn given_non_none_range_to_clauses_should_return_correct_vec_of_query_clause() {
+    fn given_non_none_range_to_clauses_should_return_correct_bool_query() {
         let criteria = SearchCriteria {
             fund_info_risk_spectrum: Some(Range {
                 lower_bound: Some(1),
@@ -201,24 +1025,27 @@ mod tests {
                 upper_bound: Some(6),
             }),
         };
-        let clauses: Vec<QueryClause> = criteria.to_clauses();
-        let expected = vec![
-            QueryClause::Range {
-                field: "risk_spectrum".into(),
-                lte: BigDecimal::from_i32(10).unwrap(),
-                gte: BigDecimal::from_i32(1).unwrap(),
-            },
-            QueryClause::Range {
-                field: "fund_statistics.return_ytd".into(),
-                lte: BigDecimal::from_i32(6).unwrap(),
-                gte: BigDecimal::from_i32(5).unwrap(),
-            },
-        ];
+        let clauses = criteria.to_clauses();
+        let expected = BoolQuery {
+            filter: vec![
+                Clause::from(QueryClause::Range {
+                    field: "risk_spectrum".into(),
+                    lte: BigDecimal::from_i32(10).unwrap(),
+                    gte: BigDecimal::from_i32(1).unwrap(),
+                }),
+                Clause::from(QueryClause::Range {
+                    field: "fund_statistics.return_ytd".into(),
+                    lte: BigDecimal::from_i32(6).unwrap(),
+                    gte: BigDecimal::from_i32(5).unwrap(),
+                }),
+            ],
+            ..Default::default()
+        };
         assert_eq!(expected, clauses,)
     }
 
     #[test]
-    fn given_one_none_range_to_clauses_should_return_correct_vec_of_query_clause() {
+    fn given_one_none_range_to_clauses_should_return_correct_bool_query() {
         let criteria = SearchCriteria {
             fund_info_risk_spectrum: None,
             fund_statistics_return_ytd: Some(Range {
@@ -226,15 +1053,71 @@ mod tests {
                 upper_bound: Some(6),
             }),
         };
-        let clauses: Vec<QueryClause> = criteria.to_clauses();
-        let expected = vec![QueryClause::Range {
-            field: "fund_statistics.return_ytd".into(),
-            lte: BigDecimal::from_i32(6).unwrap(),
-            gte: BigDecimal::from_i32(5).unwrap(),
-        }];
+        let clauses = criteria.to_clauses();
+        let expected = BoolQuery {
+            filter: vec![Clause::from(QueryClause::Range {
+                field: "fund_statistics.return_ytd".into(),
+                lte: BigDecimal::from_i32(6).unwrap(),
+                gte: BigDecimal::from_i32(5).unwrap(),
+            })],
+            ..Default::default()
+        };
         assert_eq!(expected, clauses,)
     }
 
+    #[derive(Clauseable)]
+    pub struct SearchCriteriaWithKinds {
+        #[search_field("status", kind = "terms")]
+        pub status: Option<Vec<String>>,
+        #[search_field("fund_name", kind = "prefix", case_insensitive)]
+        pub fund_name: Option<String>,
+        #[search_field("excluded_fund_id", kind = "terms", negate)]
+        pub excluded_fund_ids: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn to_clauses_should_build_terms_and_prefix_clauses_via_kind() {
+        let criteria = SearchCriteriaWithKinds {
+            status: Some(vec!["active".to_string()]),
+            fund_name: Some("k-".to_string()),
+            excluded_fund_ids: None,
+        };
+        let clauses = criteria.to_clauses();
+        let expected = BoolQuery {
+            filter: vec![
+                Clause::from(QueryClause::Terms {
+                    field: "status".into(),
+                    search_val: vec!["active".to_string()],
+                }),
+                Clause::from(QueryClause::Prefix {
+                    field: "fund_name".into(),
+                    search_val: "k-".to_string(),
+                    is_case_insensitive: true,
+                }),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(expected, clauses);
+    }
+
+    #[test]
+    fn to_clauses_should_route_negated_fields_into_must_not() {
+        let criteria = SearchCriteriaWithKinds {
+            status: None,
+            fund_name: None,
+            excluded_fund_ids: Some(vec!["1".to_string(), "2".to_string()]),
+        };
+        let clauses = criteria.to_clauses();
+        let expected = BoolQuery {
+            must_not: vec![Clause::from(QueryClause::Terms {
+                field: "excluded_fund_id".into(),
+                search_val: vec!["1".to_string(), "2".to_string()],
+            })],
+            ..Default::default()
+        };
+        assert_eq!(expected, clauses);
+    }
+
     #[test]
     fn query_match_clause_should_serialize_correctly() {
         let expect = json!({
@@ -331,4 +1214,439 @@ mod tests {
 
         assert_eq!(expect, json!(query).to_string());
     }
+
+    #[test]
+    fn query_wildcard_clause_should_serialize_correctly() {
+        let expect = json!({
+          "wildcard": {
+            "fund_code" : {
+                "value": "k-*",
+                "case_insensitive": true
+            }
+          }
+        })
+        .to_string();
+        let query = QueryClause::Wildcard {
+            field: "fund_code".into(),
+            value: "k-*".to_string(),
+            case_insensitive: true,
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn query_regexp_clause_should_serialize_correctly() {
+        let expect = json!({
+          "regexp": {
+            "fund_code" : {
+                "value": "k-.*",
+                "flags": "ALL",
+                "case_insensitive": true
+            }
+          }
+        })
+        .to_string();
+        let query = QueryClause::Regexp {
+            field: "fund_code".into(),
+            value: "k-.*".to_string(),
+            flags: Some("ALL".to_string()),
+            case_insensitive: true,
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn query_regexp_clause_should_skip_flags_when_none() {
+        let expect = json!({
+          "regexp": {
+            "fund_code" : {
+                "value": "k-.*",
+                "case_insensitive": false
+            }
+          }
+        })
+        .to_string();
+        let query = QueryClause::Regexp {
+            field: "fund_code".into(),
+            value: "k-.*".to_string(),
+            flags: None,
+            case_insensitive: false,
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn query_exists_clause_should_serialize_correctly() {
+        let expect = json!({
+            "exists": {
+                "field": "fund_code"
+            }
+        })
+        .to_string();
+        let query = QueryClause::Exists {
+            field: "fund_code".into(),
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn query_ids_clause_should_serialize_correctly() {
+        let expect = json!({
+            "ids": {
+                "values": ["1", "2", "4"]
+            }
+        })
+        .to_string();
+        let query = QueryClause::Ids {
+            values: vec!["1".to_string(), "2".to_string(), "4".to_string()],
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn query_match_clause_should_round_trip_through_json() {
+        let query = QueryClause::Match {
+            field: "fund_name".into(),
+            search_val: "global".into(),
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_range_clause_should_round_trip_through_json() {
+        let query = QueryClause::Range {
+            field: "risk_spectrum".into(),
+            gte: BigDecimal::from_i32(2).unwrap(),
+            lte: BigDecimal::from_i32(5).unwrap(),
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_terms_clause_should_round_trip_through_json() {
+        let query = QueryClause::Terms {
+            field: "fund_id".into(),
+            search_val: vec!["1".to_string(), "2".to_string(), "4".to_string()],
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_prefix_clause_should_round_trip_through_json() {
+        let query = QueryClause::Prefix {
+            field: "fund_code".into(),
+            search_val: "k-ghealth".to_string(),
+            is_case_insensitive: true,
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_prefix_clause_should_default_case_insensitive_to_false_when_absent() {
+        let query: QueryClause =
+            serde_json::from_str(r#"{"prefix": {"fund_code": {"value": "k-ghealth"}}}"#).unwrap();
+        assert_eq!(
+            QueryClause::Prefix {
+                field: "fund_code".into(),
+                search_val: "k-ghealth".to_string(),
+                is_case_insensitive: false,
+            },
+            query
+        );
+    }
+
+    #[test]
+    fn query_wildcard_clause_should_round_trip_through_json() {
+        let query = QueryClause::Wildcard {
+            field: "fund_code".into(),
+            value: "k-*".to_string(),
+            case_insensitive: true,
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_regexp_clause_should_round_trip_through_json() {
+        let query = QueryClause::Regexp {
+            field: "fund_code".into(),
+            value: "k-[0-9]+".to_string(),
+            flags: Some("ALL".to_string()),
+            case_insensitive: true,
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_exists_clause_should_round_trip_through_json() {
+        let query = QueryClause::Exists {
+            field: "fund_code".into(),
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_ids_clause_should_round_trip_through_json() {
+        let query = QueryClause::Ids {
+            values: vec!["1".to_string(), "2".to_string()],
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_fuzzy_clause_should_round_trip_through_json() {
+        let query = QueryClause::Fuzzy {
+            field: "fund_name".into(),
+            value: "global".to_string(),
+            fuzziness: Fuzziness::Auto { low: 3, high: 6 },
+            prefix_length: Some(1),
+            max_expansions: Some(50),
+        };
+        let json = json!(query).to_string();
+        assert_eq!(query, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_sort_should_round_trip_through_json() {
+        let sort = QuerySort {
+            field_name: "risk_spectrum".into(),
+            ordering: SortType::Desc,
+        };
+        let json = json!(sort).to_string();
+        assert_eq!(sort, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn query_fuzzy_clause_should_serialize_correctly_with_all_fields() {
+        let expect = json!({
+          "fuzzy": {
+            "fund_name" : {
+                "value": "global",
+                "fuzziness": 2,
+                "prefix_length": 1,
+                "max_expansions": 50
+            }
+          }
+        })
+        .to_string();
+        let query = QueryClause::Fuzzy {
+            field: "fund_name".into(),
+            value: "global".to_string(),
+            fuzziness: Fuzziness::Exact(2),
+            prefix_length: Some(1),
+            max_expansions: Some(50),
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn query_fuzzy_clause_should_skip_absent_optional_fields() {
+        let expect = json!({
+          "fuzzy": {
+            "fund_name" : {
+                "value": "global",
+                "fuzziness": "AUTO"
+            }
+          }
+        })
+        .to_string();
+        let query = QueryClause::Fuzzy {
+            field: "fund_name".into(),
+            value: "global".to_string(),
+            fuzziness: Fuzziness::Auto { low: 3, high: 6 },
+            prefix_length: None,
+            max_expansions: None,
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn fuzziness_auto_should_serialize_as_auto_with_bounds_when_non_default() {
+        let fuzziness = Fuzziness::Auto { low: 4, high: 8 };
+        assert_eq!(json!("AUTO:4,8"), json!(fuzziness));
+    }
+
+    #[test]
+    fn fuzziness_auto_should_compute_allowed_edits_per_term_length() {
+        let fuzziness = Fuzziness::Auto { low: 3, high: 6 };
+        assert_eq!(0, fuzziness.allowed_edits(2));
+        assert_eq!(1, fuzziness.allowed_edits(3));
+        assert_eq!(1, fuzziness.allowed_edits(5));
+        assert_eq!(2, fuzziness.allowed_edits(6));
+    }
+
+    #[test]
+    fn query_clause_builder_should_match_manually_constructed_clause() {
+        let built = QueryClause::match_("fund_name", "global");
+        let manual = QueryClause::Match {
+            field: "fund_name".into(),
+            search_val: "global".into(),
+        };
+        assert_eq!(manual, built);
+    }
+
+    #[test]
+    fn boosted_match_clause_should_serialize_to_expanded_form() {
+        let expect = json!({
+            "match": {
+                "fund_name": {
+                    "query": "global",
+                    "boost": 2.0
+                }
+            }
+        })
+        .to_string();
+        let query = QueryClause::match_("fund_name", "global").boost(2.0);
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn boosted_terms_clause_should_put_boost_alongside_field() {
+        let expect = json!({
+            "terms": {
+                "fund_id": ["1", "2"],
+                "boost": 1.5
+            }
+        })
+        .to_string();
+        let query =
+            QueryClause::terms("fund_id", vec!["1".to_string(), "2".to_string()]).boost(1.5);
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn unboosted_clause_should_serialize_identically_to_plain_query_clause() {
+        let plain = QueryClause::match_("fund_name", "global");
+        let boosted = plain.clone().boost(1.0);
+        let unboosted = Boosted {
+            inner: plain.clone(),
+            boost: None,
+        };
+
+        assert_eq!(json!(plain).to_string(), json!(unboosted).to_string());
+        assert_ne!(json!(plain).to_string(), json!(boosted).to_string());
+    }
+
+    #[test]
+    fn bool_query_should_skip_empty_clause_lists_when_serialized() {
+        let expect = json!({
+            "bool": {
+                "must": [
+                    { "match": { "fund_name": "global" } }
+                ]
+            }
+        })
+        .to_string();
+        let query = BoolQuery {
+            must: vec![QueryClause::Match {
+                field: "fund_name".into(),
+                search_val: "global".into(),
+            }
+            .into()],
+            ..Default::default()
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn bool_query_should_serialize_all_clause_lists_and_minimum_should_match() {
+        let expect = json!({
+            "bool": {
+                "must": [
+                    { "match": { "fund_name": "global" } }
+                ],
+                "should": [
+                    { "terms": { "fund_id": ["1", "2"] } }
+                ],
+                "must_not": [
+                    { "prefix": { "fund_code": { "value": "k-", "case_insensitive": false } } }
+                ],
+                "filter": [
+                    {
+                        "range": {
+                            "risk_spectrum": { "gte": "1", "lte": "5" }
+                        }
+                    }
+                ],
+                "minimum_should_match": 1
+            }
+        })
+        .to_string();
+        let query = BoolQuery {
+            must: vec![QueryClause::Match {
+                field: "fund_name".into(),
+                search_val: "global".into(),
+            }
+            .into()],
+            should: vec![QueryClause::Terms {
+                field: "fund_id".into(),
+                search_val: vec!["1".to_string(), "2".to_string()],
+            }
+            .into()],
+            must_not: vec![QueryClause::Prefix {
+                field: "fund_code".into(),
+                search_val: "k-".to_string(),
+                is_case_insensitive: false,
+            }
+            .into()],
+            filter: vec![QueryClause::Range {
+                field: "risk_spectrum".into(),
+                gte: BigDecimal::from_i32(1).unwrap(),
+                lte: BigDecimal::from_i32(5).unwrap(),
+            }
+            .into()],
+            minimum_should_match: Some(1),
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
+
+    #[test]
+    fn bool_query_should_serialize_nested_bool_query() {
+        let expect = json!({
+            "bool": {
+                "filter": [
+                    {
+                        "bool": {
+                            "should": [
+                                { "match": { "fund_name": "global" } }
+                            ]
+                        }
+                    }
+                ]
+            }
+        })
+        .to_string();
+        let nested = BoolQuery {
+            should: vec![QueryClause::Match {
+                field: "fund_name".into(),
+                search_val: "global".into(),
+            }
+            .into()],
+            ..Default::default()
+        };
+        let query = BoolQuery {
+            filter: vec![nested.into()],
+            ..Default::default()
+        };
+
+        assert_eq!(expect, json!(query).to_string());
+    }
 }
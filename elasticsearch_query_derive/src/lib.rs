@@ -1,13 +1,26 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{self, parse_macro_input, DataStruct, DeriveInput, Fields, Ident};
+use syn::parse::{Parse, ParseStream};
+use syn::{self, parse_macro_input, DataStruct, DeriveInput, Fields, Ident, LitStr, Token};
 
-/// A derive proc macro for generating `Vec<QueryClause>` from Graphql Criteria struct.
-/// Use `to_clauses` function to get a list of query clauses.
-/// All field must be `Option<T: ToClause>` type.
-/// Find an example in `elasticsearch_query::dsl::tests`
+/// A derive proc macro for generating a `BoolQuery` from a GraphQL Criteria
+/// struct. Use `to_clauses` to get the compound query.
+/// All fields must be `Option<T>`.
+///
+/// Each field is annotated with `#[search_field(...)]`:
+/// - `#[search_field("field_name")]` calls `T::to_clause` (`T: ToClause`),
+///   matching the field's underlying value to whatever clause the type maps
+///   to (e.g. a range).
+/// - `#[search_field("field_name", kind = "terms")]` builds a `terms` clause
+///   directly from an `Option<Vec<String>>` field.
+/// - `#[search_field("field_name", kind = "prefix", case_insensitive)]`
+///   builds a `prefix` clause directly from an `Option<String>` field.
+/// - `negate` routes the produced clause into `must_not` instead of `filter`.
+///
+/// Find an example in `elasticsearch_query::dsl::tests`.
 #[proc_macro_derive(Clauseable, attributes(search_field))]
 pub fn clausable_derive(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, data, .. } = parse_macro_input!(input);
@@ -20,35 +33,121 @@ pub fn clausable_derive(input: TokenStream) -> TokenStream {
     }
 }
 
-fn impl_to_clauses(struct_name: &Ident, fields: Fields) -> TokenStream {
-    const FIELD_ATTR_NAME: &str = "search_field";
+const FIELD_ATTR_NAME: &str = "search_field";
 
-    let mut vec_push_expr = vec![];
+/// The parsed contents of a `#[search_field(...)]` attribute.
+struct SearchFieldAttr {
+    field_name: LitStr,
+    kind: Option<LitStr>,
+    case_insensitive: bool,
+    negate: bool,
+}
 
-    for field in fields {
-        if let Some(search_field_attr) = field
-            .attrs
-            .iter()
-            .find(|a| a.path.is_ident(FIELD_ATTR_NAME))
-        {
-            let search_field_value: syn::LitStr = search_field_attr.parse_args().unwrap();
-            let field_ident = &field.ident.unwrap();
-            vec_push_expr.push(quote! {
-
-                if let Some(ref criteria_value) = self.#field_ident {
-                    clauses.push(criteria_value.to_clause(#search_field_value.into()));
-                }
+impl Parse for SearchFieldAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let field_name: LitStr = input.parse()?;
+        let mut kind = None;
+        let mut case_insensitive = false;
+        let mut negate = false;
 
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let option: Ident = input.parse()?;
+            if option == "kind" {
+                input.parse::<Token![=]>()?;
+                kind = Some(input.parse()?);
+            } else if option == "case_insensitive" {
+                case_insensitive = true;
+            } else if option == "negate" {
+                negate = true;
+            } else {
+                return Err(syn::Error::new(
+                    option.span(),
+                    format!("unknown search_field option `{option}`"),
+                ));
+            }
+        }
+
+        Ok(SearchFieldAttr {
+            field_name,
+            kind,
+            case_insensitive,
+            negate,
+        })
+    }
+}
+
+/// Builds the `QueryClause` construction expression for a field given its
+/// parsed `#[search_field(...)]` options.
+fn clause_expr(attr: &SearchFieldAttr) -> syn::Result<TokenStream2> {
+    let field_name = &attr.field_name;
+    match attr.kind.as_ref().map(LitStr::value).as_deref() {
+        None => Ok(quote! { criteria_value.to_clause(#field_name.into()) }),
+        Some("terms") => Ok(quote! { QueryClause::terms(#field_name, criteria_value.clone()) }),
+        Some("prefix") => {
+            let case_insensitive = attr.case_insensitive;
+            Ok(quote! {
+                QueryClause::prefix(#field_name, criteria_value.clone(), #case_insensitive)
             })
         }
+        Some(other) => Err(syn::Error::new(
+            attr.kind.as_ref().unwrap().span(),
+            format!("unsupported search_field kind `{other}`"),
+        )),
+    }
+}
+
+fn impl_to_clauses(struct_name: &Ident, fields: Fields) -> TokenStream {
+    let mut filter_push_exprs = vec![];
+    let mut must_not_push_exprs = vec![];
+
+    for field in fields {
+        let Some(search_field_attr) = field.attrs.iter().find(|a| a.path.is_ident(FIELD_ATTR_NAME))
+        else {
+            continue;
+        };
+
+        let attr: SearchFieldAttr = match search_field_attr.parse_args() {
+            Ok(attr) => attr,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let clause_expr = match clause_expr(&attr) {
+            Ok(expr) => expr,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let field_ident = &field.ident;
+
+        let push_expr = quote! {
+            if let Some(ref criteria_value) = self.#field_ident {
+                clauses.push(Clause::from(#clause_expr));
+            }
+        };
+
+        if attr.negate {
+            must_not_push_exprs.push(push_expr);
+        } else {
+            filter_push_exprs.push(push_expr);
+        }
     }
 
     let impl_block = quote! {
         impl #struct_name {
-            pub fn to_clauses(&self) -> Vec<QueryClause> {
-                let mut clauses = vec![];
-                #(#vec_push_expr)*
-                clauses
+            pub fn to_clauses(&self) -> BoolQuery {
+                let filter = {
+                    let mut clauses = vec![];
+                    #(#filter_push_exprs)*
+                    clauses
+                };
+                let must_not = {
+                    let mut clauses = vec![];
+                    #(#must_not_push_exprs)*
+                    clauses
+                };
+                BoolQuery {
+                    filter,
+                    must_not,
+                    ..Default::default()
+                }
             }
         }
     };